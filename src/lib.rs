@@ -54,7 +54,25 @@ let a1 = 31;
 let a2 = "text";
 let a3 = &["a", "b", "c"];
 let args = include_oracle_sql_args::map!(a1 a2 a3 => "UPDATE xxx SET a = " :a1 ", :b = " :a2 " WHERE c IN (" #a3 ")");
-assert_eq!(args, (31, "text", &["a", "b", "c"]));
+assert_eq!(args.0, 31);
+assert_eq!(args.1, "text");
+assert_eq!(args.2.0, ":A3_0, :A3_1, :A3_2");
+assert_eq!(args.2.1, vec![("A3_0".to_string(), &"a"), ("A3_1".to_string(), &"b"), ("A3_2".to_string(), &"c")]);
+```
+
+An `#arg` marker is used for arguments that are bound as Oracle `IN (...)` lists rather than as a
+single scalar value. As `oracle::Statement` has no way to bind a Rust slice directly, `map!` expands
+it, at call time, into one named placeholder per element - `:ARG_0, :ARG_1, ...`, `ARG` being the
+uppercased argument name - and returns that
+placeholder fragment together with the expanded `(name, &element)` binds so both can be spliced into
+the query. An empty slice expands to the `NULL` placeholder (and no binds) so `IN (#arg)` never becomes
+the invalid `IN ()`.
+
+```
+let a3: &[&str] = &[];
+let args = include_oracle_sql_args::map!(a3 => "WHERE c IN (" #a3 ")");
+assert_eq!(args.0, "NULL");
+assert!(args.1.is_empty());
 ```
 
 ### Duplicate SQL Parameters
@@ -78,11 +96,11 @@ let a1 = 31;
 let a2 = "text";
 let a3 = &["a", "b", "c"];
 let args = include_oracle_sql_args::map!(a1 a2 a3 => "UPDATE xxx SET a = " :a2 ", :b = " :a1 " WHERE c IN (" #a3 ")");
-assert_eq!(args, (
-    ("A1", 31), 
-    ("A2", "text"),
-    ("A3", &["a", "b", "c"]),
-));
+assert_eq!(args.0, ("A1", 31));
+assert_eq!(args.1, ("A2", "text"));
+assert_eq!(args.2.0, "A3");
+assert_eq!(args.2.1.0, ":A3_0, :A3_1, :A3_2");
+assert_eq!(args.2.1.1, vec![("A3_0".to_string(), &"a"), ("A3_1".to_string(), &"b"), ("A3_2".to_string(), &"c")]);
 ```
 
 ### Mutable (OUT) Argument
@@ -98,6 +116,54 @@ assert_eq!(args.2, ());
 args.1.push_str("TestX");
 assert_eq!(name, "TestX");
 ```
+
+### Directed OUT/INOUT Parameters
+
+A `:arg` marker is an IN bind by default. Prefixing it with `:>arg` marks it as an OUT bind and
+`:<>arg` marks it as INOUT, so the database layer knows to bind it as an output parameter and read
+its value back after execution, instead of relying on `&mut` inference. A directed argument is
+returned as a `(direction, value)` pair, `direction` being the `"OUT"` or `"INOUT"` string tag; a
+plain IN argument is returned as its bare value:
+
+```
+let id = 101;
+let mut name = String::new();
+let out_name = &mut name;
+let args = include_oracle_sql_args::map!(id out_name => "BEGIN rename(" :id ", " :>out_name ") END;");
+assert_eq!(args.0, 101);
+assert_eq!(args.1.0, "OUT");
+// emulate output
+*args.1.1 = String::from("Ship");
+assert_eq!(name, "Ship");
+```
+
+### Unknown SQL Parameter
+
+Every `:arg`/`#arg` marker must name one of the declared method arguments - a typo is caught at
+compile time instead of surfacing later as a runtime `ORA-01008: not all variables bound`:
+
+```compile_fail
+let id = 101;
+let name = "unknown";
+let args = include_oracle_sql_args::map!(id name => "UPDATE xxx SET n = " :namme " WHERE i = " :id);
+```
+
+A method argument that is declared but never referenced in the SQL is not flagged - stable Rust
+proc-macros have no API to emit a warning, only a hard error, and an unused argument is not a
+correctness problem the way an unknown one is. It still comes back as a plain `(NAME, value)`
+bind, same as any other declared argument:
+
+```
+let a1 = 31;
+let a2 = "text";
+let a3 = 99;
+let args = include_oracle_sql_args::map!(a1 a2 a3 => "SET a = " :a2 ", b = " :a1);
+assert_eq!(args, (
+    ("A1", 31),
+    ("A2", "text"),
+    ("A3", 99),
+));
+```
 */
 #[proc_macro]
 pub fn map(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -106,37 +172,185 @@ pub fn map(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let mut tokens = TokenStream::new();
 
     if method_args.len() == 1 {
-        tokens.append(method_args.remove(0));
-    } else if method_args == sql_args {
+        let arg = method_args.remove(0);
+        match sql_args.iter().find(|s| s.ident == arg) {
+            Some(meta) => tokens.append_all(arg_tokens(&arg, meta)),
+            None => tokens.append(arg),
+        }
+    } else if args_match(&method_args, &sql_args) {
         let mut items = TokenStream::new();
-        if method_args.len() == 2 {
-            items.append_terminated(method_args, Punct::new(',', Spacing::Alone));
+        let exprs: Vec<TokenStream> = method_args.iter().zip(sql_args.iter())
+            .map(|(arg, meta)| arg_tokens(arg, meta))
+            .collect();
+        if exprs.len() == 2 {
+            for expr in exprs {
+                items.append_all(expr);
+                items.append(Punct::new(',', Spacing::Alone));
+            }
             let unit = TokenStream::new();
             items.append(Group::new(Delimiter::Parenthesis, unit));
         } else {
-            items.append_separated(method_args, Punct::new(',', Spacing::Alone));
-        }
-        tokens.append(Group::new(Delimiter::Parenthesis, items));
-    } else {
-        let mut items = TokenStream::new();
-        for arg in method_args {
-            if !items.is_empty() {
-                items.append(Punct::new(',', Spacing::Alone));
+            let mut first = true;
+            for expr in exprs {
+                if !first {
+                    items.append(Punct::new(',', Spacing::Alone));
+                }
+                first = false;
+                items.append_all(expr);
             }
-            let mut name_value = TokenStream::new();
-            name_value.append(Literal::string(arg.to_string().to_uppercase().as_str()));
-            name_value.append(Punct::new(',', Spacing::Alone));
-            name_value.append(arg);
-            items.append(Group::new(Delimiter::Parenthesis, name_value))
         }
         tokens.append(Group::new(Delimiter::Parenthesis, items));
+    } else {
+        tokens.append_all(named_tuple_tokens(&method_args, &sql_args));
     }
     tokens.into()
 }
 
+/**
+Maps arguments of the generated database access method into a tuple of `(NAME, value)` pairs,
+regardless of whether the method arguments happen to be declared in the same order as they are
+used in the SQL.
+
+`map!` only falls back to `(NAME, value)` pairs when reordering, duplication or an argument unused
+by the SQL makes a bare positional tuple ambiguous; otherwise it returns a positional tuple of values. Since Oracle binds
+are always by name anyway, `map_named!` gives callers a single, stable `((NAME, value), ...)` shape
+to iterate instead of having to handle both forms.
+
+## Example
+
+```
+let a1 = 31;
+let a2 = "text";
+let args = include_oracle_sql_args::map_named!(a1 a2 => "UPDATE xxx SET a = " :a1 " WHERE b = " :a2);
+assert_eq!(args, (
+    ("A1", 31),
+    ("A2", "text"),
+));
+```
+
+A single method argument still comes back as the 1-tuple `(("NAME", value),)`, not the bare pair
+`("NAME", value)`, so callers can always iterate the result the same way regardless of arity:
+
+```
+let id = 42;
+let args = include_oracle_sql_args::map_named!(id => "SELECT * FROM xxx WHERE id = " :id);
+assert_eq!(args, (("ID", 42),));
+for (name, value) in [args.0] {
+    assert_eq!(name, "ID");
+    assert_eq!(value, 42);
+}
+```
+*/
+#[proc_macro]
+pub fn map_named(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let MapArgs { method_args, sql_args } = syn::parse_macro_input!(input as MapArgs);
+    named_tuple_tokens(&method_args, &sql_args).into()
+}
+
+/// Builds the `((NAME, value), ...)` tuple shared by `map!`'s reordered/duplicate branch and `map_named!`.
+fn named_tuple_tokens(method_args: &[syn::Ident], sql_args: &[SqlArg]) -> TokenStream {
+    let mut items = TokenStream::new();
+    for (i, arg) in method_args.iter().enumerate() {
+        let mut name_value = TokenStream::new();
+        name_value.append(Literal::string(arg.to_string().to_uppercase().as_str()));
+        name_value.append(Punct::new(',', Spacing::Alone));
+        // An arg not referenced in the SQL still gets a plain scalar bind - it is simply unused
+        // by the query text, not a reason to fail codegen.
+        match sql_args.iter().find(|s| &s.ident == arg) {
+            Some(meta) => name_value.append_all(arg_tokens(arg, meta)),
+            None => name_value.append(arg.clone()),
+        }
+        items.append(Group::new(Delimiter::Parenthesis, name_value));
+        // A single-element tuple needs a trailing comma or Rust collapses it to a bare pair.
+        if method_args.len() == 1 || i + 1 < method_args.len() {
+            items.append(Punct::new(',', Spacing::Alone));
+        }
+    }
+    let mut tokens = TokenStream::new();
+    tokens.append(Group::new(Delimiter::Parenthesis, items));
+    tokens
+}
+
+/// Returns `true` when both argument lists name the same method arguments in the same order.
+fn args_match(method_args: &[syn::Ident], sql_args: &[SqlArg]) -> bool {
+    method_args.len() == sql_args.len()
+        && method_args.iter().zip(sql_args.iter()).all(|(m, s)| m == &s.ident)
+}
+
+/// Builds the expression used in place of a bare method argument: the argument itself for a plain
+/// IN scalar bind, the expanded `(sql, binds)` pair for an `#arg` IN-list bind, or a
+/// `("OUT"/"INOUT", value)` pair for an explicitly directed `:>arg`/`:<>arg` bind.
+fn arg_tokens(arg: &syn::Ident, meta: &SqlArg) -> TokenStream {
+    if meta.is_list {
+        list_tokens(arg)
+    } else if meta.direction != Direction::In {
+        let direction = direction_tokens(meta.direction);
+        let mut pair = TokenStream::new();
+        pair.append_all(direction);
+        pair.append(Punct::new(',', Spacing::Alone));
+        pair.append(arg.clone());
+        let mut tokens = TokenStream::new();
+        tokens.append(Group::new(Delimiter::Parenthesis, pair));
+        tokens
+    } else {
+        let mut tokens = TokenStream::new();
+        tokens.append(arg.clone());
+        tokens
+    }
+}
+
+/// Builds the `"IN"`/`"OUT"`/`"INOUT"` string literal that tags a directed bind in generated code.
+fn direction_tokens(direction: Direction) -> TokenStream {
+    let literal = match direction {
+        Direction::In => "IN",
+        Direction::Out => "OUT",
+        Direction::InOut => "INOUT",
+    };
+    let mut tokens = TokenStream::new();
+    tokens.append(Literal::string(literal));
+    tokens
+}
+
+/// Builds the `(placeholder_sql, binds)` expression that expands an `#arg` IN-list bind at call time.
+fn list_tokens(arg: &syn::Ident) -> TokenStream {
+    let arg = arg.to_string();
+    let name = arg.to_uppercase();
+    let src = format!(r#"{{
+        let __names: ::std::vec::Vec<::std::string::String> = (0..{arg}.len()).map(|__i| ::std::format!(":{{}}_{{}}", "{name}", __i)).collect();
+        let __sql = if __names.is_empty() {{ ::std::string::String::from("NULL") }} else {{ __names.join(", ") }};
+        let __binds: ::std::vec::Vec<(::std::string::String, _)> = {arg}.iter().enumerate().map(|(__i, __v)| (::std::format!("{{}}_{{}}", "{name}", __i), __v)).collect();
+        (__sql, __binds)
+    }}"#, arg = arg, name = name);
+    parse_generated(src)
+}
+
+/// Parses a snippet of generated Rust source into tokens, panicking (which surfaces as a clear
+/// macro-internal panic rather than a confusing syntax error in the caller's code) if codegen ever
+/// produces invalid Rust.
+fn parse_generated(src: String) -> TokenStream {
+    src.parse().expect("generated code is valid Rust")
+}
+
+struct SqlArg {
+    ident: syn::Ident,
+    is_list: bool,
+    direction: Direction,
+}
+
+/// Direction of a bind parameter, as set by the `:arg` (IN, the default), `:>arg` (OUT) and
+/// `:<>arg` (INOUT) SQL marker sigils. A proc-macro crate cannot export plain types, so this is
+/// surfaced to generated code as the `"OUT"`/`"INOUT"` string literal of a directed bind rather than
+/// as a type; an IN bind is passed as its bare value, without a tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    In,
+    Out,
+    InOut,
+}
+
 struct MapArgs {
     method_args: Vec<syn::Ident>,
-    sql_args: Vec<syn::Ident>,
+    sql_args: Vec<SqlArg>,
 }
 
 impl Parse for MapArgs {
@@ -154,16 +368,128 @@ impl Parse for MapArgs {
                 break;
             }
             let variant = input.lookahead1();
+            let is_list;
+            let direction;
             if variant.peek(Token![:]) {
                 let _ : Token![:] = input.parse()?;
+                is_list = false;
+                direction = if input.peek(Token![>]) {
+                    let _ : Token![>] = input.parse()?;
+                    Direction::Out
+                } else if input.peek(Token![<]) {
+                    let _ : Token![<] = input.parse()?;
+                    let _ : Token![>] = input.parse()?;
+                    Direction::InOut
+                } else {
+                    Direction::In
+                };
             } else if variant.peek(Token![#]) {
                 let _ : Token![#] = input.parse()?;
+                is_list = true;
+                direction = Direction::In;
             } else {
                 return Err(variant.error());
             }
-            let arg = input.parse()?;
-            sql_args.push(arg);
+            let arg: syn::Ident = input.parse()?;
+            if !method_args.iter().any(|m| m == &arg) {
+                return Err(syn::Error::new_spanned(
+                    &arg,
+                    format!("`{}` is not among the declared method arguments", arg),
+                ));
+            }
+            sql_args.push(SqlArg { ident: arg, is_list, direction });
         }
         Ok(Self { method_args, sql_args })
     }
 }
+
+/**
+Maps a method argument that is a slice of rows into a tuple of `(NAME, column)` pairs, one per
+distinct field referenced in the SQL, for Oracle's array DML (one prepared statement executed over
+many parameter rows in a single round trip). Unlike `map!`/`map_named!`, the `:field` markers here
+name fields of an element of the rows slice rather than other method arguments, so each column is
+expanded into a `Vec` collected from every row.
+
+## Example
+
+```
+struct Row {
+    a: i32,
+    b: &'static str,
+}
+let rows = vec![
+    Row { a: 1, b: "x" },
+    Row { a: 2, b: "y" },
+];
+let args = include_oracle_sql_args::map_batch!(rows => "INSERT INTO t(a,b) VALUES (" :a ", " :b ")");
+assert_eq!(args.0, ("A", vec![&1, &2]));
+assert_eq!(args.1, ("B", vec![&"x", &"y"]));
+```
+*/
+#[proc_macro]
+pub fn map_batch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let BatchArgs { rows, columns } = syn::parse_macro_input!(input as BatchArgs);
+
+    let mut seen = Vec::new();
+    let mut exprs = Vec::new();
+    for field in &columns {
+        if seen.iter().any(|f| f == field) {
+            continue;
+        }
+        seen.push(field.clone());
+        exprs.push(column_tokens(&rows, field));
+    }
+    tuple_tokens(exprs).into()
+}
+
+/// Builds the `(NAME, rows.iter().map(|row| &row.field).collect::<Vec<_>>())` expression for one
+/// batch column.
+fn column_tokens(rows: &syn::Ident, field: &syn::Ident) -> TokenStream {
+    let rows = rows.to_string();
+    let field = field.to_string();
+    let name = field.to_uppercase();
+    let src = format!(
+        r#"("{name}", {rows}.iter().map(|__row| &__row.{field}).collect::<::std::vec::Vec<_>>())"#,
+        name = name, rows = rows, field = field,
+    );
+    parse_generated(src)
+}
+
+/// Assembles a tuple expression from its element expressions, adding the trailing comma a
+/// single-element tuple needs to not be parsed as a parenthesized expression.
+fn tuple_tokens(exprs: Vec<TokenStream>) -> TokenStream {
+    let len = exprs.len();
+    let mut items = TokenStream::new();
+    for (i, expr) in exprs.into_iter().enumerate() {
+        items.append_all(expr);
+        if len == 1 || i + 1 < len {
+            items.append(Punct::new(',', Spacing::Alone));
+        }
+    }
+    let mut tokens = TokenStream::new();
+    tokens.append(Group::new(Delimiter::Parenthesis, items));
+    tokens
+}
+
+struct BatchArgs {
+    rows: syn::Ident,
+    columns: Vec<syn::Ident>,
+}
+
+impl Parse for BatchArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let rows: syn::Ident = input.parse()?;
+        let _ : Token![=>] = input.parse()?;
+        let mut columns = Vec::new();
+        while !input.is_empty() {
+            let _ : syn::LitStr = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            let _ : Token![:] = input.parse()?;
+            let field: syn::Ident = input.parse()?;
+            columns.push(field);
+        }
+        Ok(Self { rows, columns })
+    }
+}